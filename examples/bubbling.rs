@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug)]
+struct Click;
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_targeted_event_and_listen::<Click>()
+    .add_startup_system(|mut commands: Commands| {
+        let mut panel_listener = EventListener::new();
+        panel_listener.add_bubbling(|_world: &mut World, _click: &Click, ctx: &mut BubbleContext| {
+            println!("panel {:?} handled the click from {:?}", ctx.current_entity(), ctx.target());
+            ctx.stop_propagation();
+        });
+        let panel = commands.spawn().insert(panel_listener).id();
+
+        let mut button_listener = EventListener::new();
+        button_listener.add_bubbling(|_world: &mut World, _click: &Click, ctx: &mut BubbleContext| {
+            println!("click seen at button {:?}, bubbling up", ctx.current_entity());
+        });
+        let button = commands.spawn().insert(button_listener).id();
+        commands.entity(panel).push_children(&[button]);
+
+        commands.insert_resource(Button(button));
+    })
+    .add_startup_system(|button: Res<Button>, mut writer: EventWriter<EntityEvent<Click>>| {
+        writer.send_to(button.0, Click);
+    })
+    .run();
+}
+
+struct Button(Entity);
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug)]
+struct Burning;
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_component_lifecycle_listen::<Burning>()
+    .add_startup_system(|mut commands: Commands| {
+        let event_listener = EventListener::<ComponentAdded<Burning>>::on_added(|_world, entity| {
+            println!("{:?} caught fire", entity);
+        });
+        commands.spawn().insert(event_listener);
+    })
+    .add_system(|mut commands: Commands, query: Query<Entity, Without<Burning>>| {
+        for entity in query.iter() {
+            commands.entity(entity).insert(Burning);
+        }
+    })
+    .run();
+}
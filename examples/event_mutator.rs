@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug, Clone)]
+struct Damage(i32);
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_event_and_listen::<Damage>()
+    .add_startup_system(|mut commands: Commands| {
+        let mut event_listener = EventListener::event_mutator(|damage: &mut Damage| {
+            damage.0 *= 2;
+        });
+        event_listener.add(|_world: &mut World, damage: &Damage, _entity: Entity| {
+            println!("recieved Damage after mutation: {:?}", damage);
+        });
+        commands.spawn().insert(event_listener);
+    })
+    .add_startup_system(|mut writer: EventWriter<Damage>| {
+        writer.send(Damage(10));
+    })
+    .run();
+}
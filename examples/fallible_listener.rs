@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug)]
+struct Withdraw(i32);
+
+#[derive(Component, Debug)]
+struct Balance(i32);
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_event_and_listen::<Withdraw>()
+    .add_startup_system(|mut commands: Commands| {
+        let mut event_listener = EventListener::new();
+        event_listener.try_add(|world: &mut World, withdraw: &Withdraw, entity: Entity| {
+            let mut balance = world.get_mut::<Balance>(entity).unwrap();
+            if withdraw.0 > balance.0 {
+                return Err(format!("insufficient balance: have {}, need {}", balance.0, withdraw.0).into());
+            }
+            balance.0 -= withdraw.0;
+            Ok(())
+        });
+        commands.spawn().insert(Balance(10)).insert(event_listener);
+    })
+    .add_startup_system(|mut writer: EventWriter<Withdraw>| {
+        writer.send(Withdraw(25));
+    })
+    .add_system(|mut errors: EventReader<ListenerError>| {
+        for error in errors.iter() {
+            println!("listener error on {:?}: {}", error.entity, error.error);
+        }
+    })
+    .run();
+}
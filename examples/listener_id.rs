@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug)]
+struct Ping;
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_event_and_listen::<Ping>()
+    .add_startup_system(|mut commands: Commands| {
+        let mut event_listener = EventListener::new();
+        let noisy = event_listener.add(|_world: &mut World, _ping: &Ping, _entity: Entity| {
+            println!("noisy handler heard a Ping");
+        });
+        event_listener.add(|_world: &mut World, _ping: &Ping, _entity: Entity| {
+            println!("quiet handler heard a Ping");
+        });
+
+        // Detach the noisy handler before it ever runs; only "quiet handler" prints.
+        let removed = event_listener.remove(noisy);
+        println!("removed noisy handler: {}", removed);
+
+        commands.spawn().insert(event_listener);
+    })
+    .add_startup_system(|mut writer: EventWriter<Ping>| {
+        writer.send(Ping);
+    })
+    .run();
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug, Clone)]
+struct Score(i32);
+
+#[derive(Component)]
+struct Player;
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_event_and_listen::<Score>()
+    .add_startup_system(|mut commands: Commands| {
+        commands.spawn().insert(EventListener::<Score>::system(
+            |trigger: Trigger<Score>, players: Query<Entity, With<Player>>| {
+                println!(
+                    "Score {:?} landed while {} players are on the board",
+                    trigger.event(),
+                    players.iter().count()
+                );
+            },
+        ));
+        commands.spawn().insert(Player);
+        commands.spawn().insert(Player);
+    })
+    .add_startup_system(|mut writer: EventWriter<Score>| {
+        writer.send(Score(3));
+    })
+    .run();
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use bevy_event_listener_component::*;
+
+#[derive(Component, Debug)]
+struct Knock;
+
+fn main() {
+    App::new()
+    .add_plugins(MinimalPlugins)
+    .add_plugin(EventListenerComponentPlugin)
+    .add_targeted_event_and_listen::<Knock>()
+    .add_startup_system(|mut commands: Commands| {
+        let mut event_listener = EventListener::new();
+        event_listener.add(|_world: &mut World, _knock: &Knock, entity: Entity| {
+            println!("{:?} answers the door", entity);
+        });
+        let front_door = commands.spawn().insert(event_listener).id();
+
+        // A second entity with its own listener never sees this targeted knock.
+        let mut other_listener = EventListener::new();
+        other_listener.add(|_world: &mut World, _knock: &Knock, entity: Entity| {
+            println!("{:?} should never print this", entity);
+        });
+        commands.spawn().insert(other_listener);
+
+        commands.insert_resource(FrontDoor(front_door));
+    })
+    .add_startup_system(|front_door: Res<FrontDoor>, mut writer: EventWriter<EntityEvent<Knock>>| {
+        writer.send_to(front_door.0, Knock);
+    })
+    .run();
+}
+
+struct FrontDoor(Entity);
@@ -6,12 +6,22 @@ use std::sync::Arc;
 use std::sync::Mutex;
 use bevy::app::Events;
 use bevy::app::ManualEventReader;
+use bevy::ecs::system::IntoSystem;
+use bevy::ecs::system::System;
+use bevy::ecs::system::SystemParam;
 use bevy::ecs::system::SystemState;
 use bevy::prelude::*;
 use bevy::utils::HashMap;
+use bevy::utils::HashSet;
 
 
-pub trait HandlesEvent<E> 
+/// Identifies a handler, mutator, bubbling handler, or fallible handler
+/// registered on an `EventListener<E>`, returned by
+/// `add`/`add_mutator`/`add_bubbling`/`try_add` and consumed by `remove`. Monotonically increasing per listener instance.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ListenerId(u32);
+
+pub trait HandlesEvent<E>
 where
     E: 'static + Send + Sync
 {
@@ -48,12 +58,164 @@ where
     }
 }
 
+pub trait HandlesEventMut<E>
+where
+    E: 'static + Send + Sync
+{
+    fn on_event_mut(&mut self, world: &mut World, event: &mut E, entity: Entity);
+}
+
+pub struct EventMutator<E, F>
+where
+    F: FnMut(&mut World, &mut E, Entity)
+{
+    closure: F,
+    phantom: PhantomData<dyn Fn() -> E + 'static + Send + Sync>
+}
+
+impl <E, F> EventMutator<E, F>
+where
+    F: FnMut(&mut World, &mut E, Entity)
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            closure: f,
+            phantom: PhantomData::default()
+        }
+    }
+}
+
+impl <E, F> HandlesEventMut<E> for EventMutator<E, F>
+where
+    E: 'static + Send + Sync,
+    F: FnMut(&mut World, &mut E, Entity) + 'static + Send + Sync
+{
+    fn on_event_mut(&mut self, world: &mut World, event: &mut E, entity: Entity) {
+        (self.closure)(world, event, entity);
+    }
+}
+
+/// Passed to a `HandlesBubbleEvent` handler as the event walks up the
+/// `Parent` chain from the original `EntityEvent` target. `current_entity`
+/// changes at each step; `target` stays fixed. Call `stop_propagation` to
+/// halt the walk once an ancestor has consumed the event.
+pub struct BubbleContext {
+    target: Entity,
+    current_entity: Entity,
+    stop: bool,
+}
+
+impl BubbleContext {
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    pub fn current_entity(&self) -> Entity {
+        self.current_entity
+    }
+
+    pub fn stop_propagation(&mut self) {
+        self.stop = true;
+    }
+}
+
+pub trait HandlesBubbleEvent<E>
+where
+    E: 'static + Send + Sync
+{
+    fn on_event_bubble(&mut self, world: &mut World, event: &E, ctx: &mut BubbleContext);
+}
+
+pub struct BubbleHandler<E, F>
+where
+    F: FnMut(&mut World, &E, &mut BubbleContext)
+{
+    closure: F,
+    phantom: PhantomData<dyn Fn() -> E + 'static + Send + Sync>
+}
+
+impl <E, F> BubbleHandler<E, F>
+where
+    F: FnMut(&mut World, &E, &mut BubbleContext)
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            closure: f,
+            phantom: PhantomData::default()
+        }
+    }
+}
+
+impl <E, F> HandlesBubbleEvent<E> for BubbleHandler<E, F>
+where
+    E: 'static + Send + Sync,
+    F: FnMut(&mut World, &E, &mut BubbleContext) + 'static + Send + Sync
+{
+    fn on_event_bubble(&mut self, world: &mut World, event: &E, ctx: &mut BubbleContext) {
+        (self.closure)(world, event, ctx);
+    }
+}
+
+/// Carries a non-fatal error reported by a `try_add` handler, drained from
+/// the `Events<ListenerError>` resource with a normal `EventReader`.
+pub struct ListenerError {
+    pub entity: Entity,
+    pub event_type: TypeId,
+    pub error: Box<dyn std::error::Error + 'static + Send + Sync>,
+}
+
+/// Dispatched by both `Processor` (untargeted `add_event_and_listen`) and
+/// `TargetedProcessor` (`add_targeted_event_and_listen`, including the
+/// component lifecycle listeners) at the addressed/broadcast entity, just
+/// like a plain `HandlesEvent`. Fallible handlers never run as part of the
+/// `Parent`-chain bubbling walk — only `HandlesBubbleEvent` handlers do.
+pub trait HandlesEventFallible<E>
+where
+    E: 'static + Send + Sync
+{
+    fn try_on_event(&mut self, world: &mut World, event: &E, entity: Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>>;
+}
+
+pub struct FallibleEventHandler<E, F>
+where
+    F: FnMut(&mut World, &E, Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>>
+{
+    closure: F,
+    phantom: PhantomData<dyn Fn() -> E + 'static + Send + Sync>
+}
+
+impl <E, F> FallibleEventHandler<E, F>
+where
+    F: FnMut(&mut World, &E, Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>>
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            closure: f,
+            phantom: PhantomData::default()
+        }
+    }
+}
+
+impl <E, F> HandlesEventFallible<E> for FallibleEventHandler<E, F>
+where
+    E: 'static + Send + Sync,
+    F: FnMut(&mut World, &E, Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>> + 'static + Send + Sync
+{
+    fn try_on_event(&mut self, world: &mut World, event: &E, entity: Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>> {
+        (self.closure)(world, event, entity)
+    }
+}
+
 #[derive(Component)]
-pub struct EventListener<E> 
+pub struct EventListener<E>
 where
     E: 'static + Send + Sync
 {
-    list: Arc<Mutex<Vec<Box<dyn HandlesEvent<E> + 'static + Send + Sync>>>>,
+    list: Arc<Mutex<Vec<(ListenerId, Box<dyn HandlesEvent<E> + 'static + Send + Sync>)>>>,
+    mutators: Arc<Mutex<Vec<(ListenerId, Box<dyn HandlesEventMut<E> + 'static + Send + Sync>)>>>,
+    bubblers: Arc<Mutex<Vec<(ListenerId, Box<dyn HandlesBubbleEvent<E> + 'static + Send + Sync>)>>>,
+    fallible: Arc<Mutex<Vec<(ListenerId, Box<dyn HandlesEventFallible<E> + 'static + Send + Sync>)>>>,
+    next_id: u32,
 }
 
 impl <E> Default for EventListener<E>
@@ -61,11 +223,17 @@ where
     E: 'static + Send + Sync
 {
     fn default() -> Self {
-        Self { list: Default::default() }
+        Self {
+            list: Default::default(),
+            mutators: Default::default(),
+            bubblers: Default::default(),
+            fallible: Default::default(),
+            next_id: 0,
+        }
     }
 }
 
-impl <E> EventListener<E> 
+impl <E> EventListener<E>
 where
     E: 'static + Send + Sync
 {
@@ -73,9 +241,15 @@ where
         EventListener::default()
     }
 
+    fn next_listener_id(&mut self) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
     pub fn mutator<C: Component>(
         mut f: impl FnMut(&E, &mut C) + 'static + Sync + Send) -> Self {
-        let listener = Self::default();
+        let mut listener = Self::default();
         let h = EventHandler::new(move |world: &mut World, event: &E, entity: Entity| {
             if let Some(mut entity_mut) = world.get_entity_mut(entity) {
                 if let Some(mut component) = entity_mut.get_mut::<C>() {
@@ -83,17 +257,144 @@ where
                 }
             }
         });
-        listener.list.lock().unwrap().push(Box::new(h));
+        let id = listener.next_listener_id();
+        listener.list.lock().unwrap().push((id, Box::new(h)));
+        listener
+    }
+
+    /// Registers a handler that rewrites the event itself before any plain
+    /// `HandlesEvent` listener observes it. Unlike `mutator`, which mutates a
+    /// sibling component, this mutates the event in place; see
+    /// `Processor::process_event` for the ordering guarantee this relies on.
+    pub fn event_mutator<F>(mut f: F) -> Self
+    where
+        F: FnMut(&mut E) + 'static + Send + Sync
+    {
+        let mut listener = Self::default();
+        let h = EventMutator::new(move |_world: &mut World, event: &mut E, _entity: Entity| f(event));
+        let id = listener.next_listener_id();
+        listener.mutators.lock().unwrap().push((id, Box::new(h)));
         listener
     }
 
-    pub fn add<F>(&mut self, f: F) -> &mut Self
-    where 
+    /// Registers a system-function handler: its first parameter is a
+    /// `Trigger<E>` exposing the firing event and entity, and every other
+    /// parameter is an ordinary `SystemParam`. Runs as an exclusive system
+    /// during `update_event_listeners`, with `Commands` applied afterward.
+    ///
+    /// `E` cannot be inferred from `sys` alone (it only shows up buried
+    /// inside `Params` via `Trigger<E>`), so call this with the event type
+    /// spelled out, e.g. `EventListener::<Score>::system(...)`.
+    pub fn system<Params>(sys: impl IntoSystem<(), (), Params> + 'static) -> Self
+    where
+        E: Clone
+    {
+        let mut listener = Self::default();
+        let system = SystemListener {
+            system: Box::new(IntoSystem::into_system(sys)),
+            initialized: false,
+            phantom: PhantomData,
+        };
+        let id = listener.next_listener_id();
+        listener.list.lock().unwrap().push((id, Box::new(system)));
+        listener
+    }
+
+    pub fn add<F>(&mut self, f: F) -> ListenerId
+    where
         F: FnMut(&mut World, &E, Entity) + 'static + Send + Sync
     {
         let handler = EventHandler::new(f);
-        self.list.lock().unwrap().push(Box::new(handler));
-        self
+        let id = self.next_listener_id();
+        self.list.lock().unwrap().push((id, Box::new(handler)));
+        id
+    }
+
+    pub fn add_mutator<F>(&mut self, f: F) -> ListenerId
+    where
+        F: FnMut(&mut World, &mut E, Entity) + 'static + Send + Sync
+    {
+        let mutator = EventMutator::new(f);
+        let id = self.next_listener_id();
+        self.mutators.lock().unwrap().push((id, Box::new(mutator)));
+        id
+    }
+
+    /// Registers a handler that also runs when a targeted event bubbles up
+    /// through the `Parent` chain past this entity; see
+    /// `TargetedProcessor::process_event` for the walk and the
+    /// `stop_propagation` invariant.
+    pub fn add_bubbling<F>(&mut self, f: F) -> ListenerId
+    where
+        F: FnMut(&mut World, &E, &mut BubbleContext) + 'static + Send + Sync
+    {
+        let handler = BubbleHandler::new(f);
+        let id = self.next_listener_id();
+        self.bubblers.lock().unwrap().push((id, Box::new(handler)));
+        id
+    }
+
+    /// Registers a handler that can report failure instead of panicking or
+    /// silently swallowing it; see `Processor::process_event` for how the
+    /// returned error ends up in `Events<ListenerError>`.
+    pub fn try_add<F>(&mut self, f: F) -> ListenerId
+    where
+        F: FnMut(&mut World, &E, Entity) -> Result<(), Box<dyn std::error::Error + 'static + Send + Sync>> + 'static + Send + Sync
+    {
+        let handler = FallibleEventHandler::new(f);
+        let id = self.next_listener_id();
+        self.fallible.lock().unwrap().push((id, Box::new(handler)));
+        id
+    }
+
+    /// Detaches a previously registered handler, mutator, bubbling handler,
+    /// or fallible handler by the `ListenerId` its registration method
+    /// returned. Returns `false` if no handler with that id is currently
+    /// registered.
+    ///
+    /// Must not be called from within a handler running on this same
+    /// `EventListener` (directly, or via `World` access reaching back into
+    /// it). Dispatch holds each handler collection's `Mutex` for the
+    /// duration of its callback loop, and that `Mutex` isn't reentrant, so
+    /// calling `remove`/`clear` on the listener that's currently dispatching
+    /// will deadlock rather than return.
+    pub fn remove(&mut self, id: ListenerId) -> bool {
+        let mut list = self.list.lock().unwrap();
+        if let Some(pos) = list.iter().position(|(i, _)| *i == id) {
+            list.remove(pos);
+            return true;
+        }
+        drop(list);
+        let mut mutators = self.mutators.lock().unwrap();
+        if let Some(pos) = mutators.iter().position(|(i, _)| *i == id) {
+            mutators.remove(pos);
+            return true;
+        }
+        drop(mutators);
+        let mut bubblers = self.bubblers.lock().unwrap();
+        if let Some(pos) = bubblers.iter().position(|(i, _)| *i == id) {
+            bubblers.remove(pos);
+            return true;
+        }
+        drop(bubblers);
+        let mut fallible = self.fallible.lock().unwrap();
+        if let Some(pos) = fallible.iter().position(|(i, _)| *i == id) {
+            fallible.remove(pos);
+            return true;
+        }
+        false
+    }
+
+    /// Detaches every handler, mutator, bubbling handler, and fallible
+    /// handler on this listener.
+    ///
+    /// Same reentrancy hazard as `remove`: never call this from within a
+    /// handler running on this same `EventListener`, or the app will hang.
+    pub fn clear(&mut self) {
+        self.list.lock().unwrap().clear();
+        self.mutators.lock().unwrap().clear();
+        self.bubblers.lock().unwrap().clear();
+        self.fallible.lock().unwrap().clear();
     }
 }
 
@@ -109,46 +410,297 @@ where
     }
 }
 
+pub struct CurrentEvent<E>
+where
+    E: 'static + Send + Sync
+{
+    event: E,
+    entity: Entity,
+}
+
+/// The first `SystemParam` of an `EventListener::system` handler. Reads the
+/// event and entity that `CurrentEvent<E>` was populated with for this call;
+/// every other parameter is an ordinary `SystemParam` (`Query`, `Res`,
+/// `Commands`, ...).
+#[derive(SystemParam)]
+pub struct Trigger<'w, 's, E>
+where
+    E: 'static + Send + Sync
+{
+    current: Res<'w, CurrentEvent<E>>,
+    #[system_param(ignore)]
+    marker: PhantomData<&'s ()>,
+}
+
+impl <'w, 's, E> Trigger<'w, 's, E>
+where
+    E: 'static + Send + Sync
+{
+    pub fn event(&self) -> &E {
+        &self.current.event
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.current.entity
+    }
+}
+
+struct SystemListener<E>
+where
+    E: 'static + Send + Sync + Clone
+{
+    system: Box<dyn System<In = (), Out = ()>>,
+    initialized: bool,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl <E> HandlesEvent<E> for SystemListener<E>
+where
+    E: 'static + Send + Sync + Clone
+{
+    fn on_event(&mut self, world: &mut World, event: &E, entity: Entity) {
+        world.insert_resource(CurrentEvent { event: event.clone(), entity });
+        if !self.initialized {
+            self.system.initialize(world);
+            self.initialized = true;
+        }
+        self.system.run((), world);
+        self.system.apply_buffers(world);
+        world.remove_resource::<CurrentEvent<E>>();
+    }
+}
+
 pub trait EventProcessor : 'static + Send + Sync {
     fn process_event(&mut self, world: &mut World);
 }
 
-pub struct Processor<E> 
-where 
+pub struct Processor<E>
+where
     E: 'static + Send + Sync
 {
-    manual_event_reader: ManualEventReader<E>
+    manual_event_reader: ManualEventReader<E>,
 }
 
-impl <E> Default for Processor<E> 
+impl <E> Default for Processor<E>
 where
     E: 'static + Send + Sync
-{        
+{
     fn default() -> Self {
         Self { manual_event_reader: Default::default() }
     }
 }
 
+impl <E> Processor<E>
+where
+    E: 'static + Send + Sync
+{
+    fn dispatch(world: &mut World, entities: &[Entity], event: &E) {
+        for &entity in entities.iter() {
+            if let Some(listener) = world.get_mut::<EventListener<E>>(entity) {
+                let list_arc = listener.list.clone();
+                let fallible_arc = listener.fallible.clone();
+                {
+                    let mut list = list_arc.lock().unwrap();
+                    for (_, handler) in list.iter_mut() {
+                        handler.on_event(world, event, entity);
+                    }
+                }
+                // Fallible handlers don't panic or get silently skipped on
+                // failure: their error is boxed up into `ListenerError` and
+                // handed off to `Events<ListenerError>` for centralized,
+                // non-fatal reporting across every listener entity.
+                let mut fallible = fallible_arc.lock().unwrap();
+                for (_, handler) in fallible.iter_mut() {
+                    if let Err(error) = handler.try_on_event(world, event, entity) {
+                        if let Some(mut errors) = world.get_resource_mut::<Events<ListenerError>>() {
+                            errors.send(ListenerError { entity, event_type: TypeId::of::<E>(), error });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
-impl <E> EventProcessor for Processor<E> 
-where 
+impl <E> EventProcessor for Processor<E>
+where
     E: 'static + Send + Sync
 {
     fn process_event(&mut self, world: &mut World) {
-        world.resource_scope(|world, events: Mut<Events<E>>| {
+        world.resource_scope(|world, mut events: Mut<Events<E>>| {
             let mut system_state = SystemState::<Query<Entity, With<EventListener<E>>>>::new(world);
             let entities: Vec<Entity> = system_state.get(world).iter().collect();
-            for event in self.manual_event_reader.iter(&events) {
-                for &entity in entities.iter() {
-                    if let Some(listener) = world.get_mut::<EventListener<E>>(entity) {
-                        let arc = listener.list.clone();
-                        let mut list = arc.lock().unwrap();
-                        for handler in list.iter_mut() {
-                            handler.on_event(world, event, entity);
+
+            let has_mutators = entities.iter().any(|&entity| {
+                world.get::<EventListener<E>>(entity)
+                    .map_or(false, |listener| !listener.mutators.lock().unwrap().is_empty())
+            });
+
+            // Mutators need `&mut E`, but `Events<E>` only exposes shared iteration
+            // publicly, so the only way to get a mutable event out is to drain the
+            // buffer. The previous approach resent each mutated event with
+            // `events.send()` so `manual_event_reader` would pick it up below, but
+            // that assigns it a fresh `EventId`; once Bevy's own buffer rotation
+            // touched a still-young resent event, it could look unread again and
+            // get redispatched forever. So instead, once any entity has a mutator
+            // registered for this event type, this processor takes ownership of
+            // the whole buffer for the frame: drain it, mutate and dispatch each
+            // event locally, and never feed it back. A mutated event type is then
+            // no longer visible to any `EventReader<E>` outside this crate's own
+            // dispatch below. Events that `manual_event_reader` already dispatched
+            // on an earlier frame can still sit in the buffer; they are the oldest
+            // ones and `drain` yields oldest first, so that many are skipped. Thus
+            // toggling mutators on and off never double-dispatches or drops an event.
+            if has_mutators {
+                let total = ManualEventReader::<E>::default().iter(&events).count();
+                let unread = self.manual_event_reader.iter(&events).count();
+                for mut event in events.drain().skip(total - unread) {
+                    for &entity in entities.iter() {
+                        if let Some(listener) = world.get_mut::<EventListener<E>>(entity) {
+                            let arc = listener.mutators.clone();
+                            let mut mutators = arc.lock().unwrap();
+                            for (_, mutator) in mutators.iter_mut() {
+                                mutator.on_event_mut(world, &mut event, entity);
+                            }
                         }
                     }
+                    Self::dispatch(world, &entities, &event);
+                }
+            } else {
+                for event in self.manual_event_reader.iter(&events) {
+                    Self::dispatch(world, &entities, event);
+                }
+            }
+        });
+    }
+}
+
+/// An event addressed at a single entity, or broadcast to every listener when
+/// `target` is `None`. Wraps a plain event `E`; the `EventListener<E>`
+/// handlers registered on the target entity still see `&E`, not the wrapper.
+pub struct EntityEvent<E> {
+    pub target: Option<Entity>,
+    pub event: E,
+}
+
+impl <E> EntityEvent<E> {
+    pub fn new(event: E) -> Self {
+        Self { target: None, event }
+    }
+
+    pub fn targeted(target: Entity, event: E) -> Self {
+        Self { target: Some(target), event }
+    }
+}
+
+pub trait EventWriterExt<E> {
+    fn send_to(&mut self, target: Entity, event: E);
+}
+
+impl <'w, 's, E> EventWriterExt<E> for EventWriter<'w, 's, EntityEvent<E>>
+where
+    E: 'static + Send + Sync
+{
+    fn send_to(&mut self, target: Entity, event: E) {
+        self.send(EntityEvent::targeted(target, event));
+    }
+}
+
+pub struct TargetedProcessor<E>
+where
+    E: 'static + Send + Sync
+{
+    manual_event_reader: ManualEventReader<EntityEvent<E>>,
+}
+
+impl <E> Default for TargetedProcessor<E>
+where
+    E: 'static + Send + Sync
+{
+    fn default() -> Self {
+        Self { manual_event_reader: Default::default() }
+    }
+}
+
+impl <E> EventProcessor for TargetedProcessor<E>
+where
+    E: 'static + Send + Sync
+{
+    fn process_event(&mut self, world: &mut World) {
+        world.resource_scope(|world, events: Mut<Events<EntityEvent<E>>>| {
+            let mut system_state = SystemState::<Query<Entity, With<EventListener<E>>>>::new(world);
+            let entities: Vec<Entity> = system_state.get(world).iter().collect();
+            for wrapped in self.manual_event_reader.iter(&events) {
+                match wrapped.target {
+                    Some(target) => {
+                        if let Some(listener) = world.get_mut::<EventListener<E>>(target) {
+                            let list_arc = listener.list.clone();
+                            let fallible_arc = listener.fallible.clone();
+                            {
+                                let mut list = list_arc.lock().unwrap();
+                                for (_, handler) in list.iter_mut() {
+                                    handler.on_event(world, &wrapped.event, target);
+                                }
+                            }
+                            let mut fallible = fallible_arc.lock().unwrap();
+                            for (_, handler) in fallible.iter_mut() {
+                                if let Err(error) = handler.try_on_event(world, &wrapped.event, target) {
+                                    if let Some(mut errors) = world.get_resource_mut::<Events<ListenerError>>() {
+                                        errors.send(ListenerError { entity: target, event_type: TypeId::of::<E>(), error });
+                                    }
+                                }
+                            }
+                        }
 
-                }   
+                        // Bubble: walk the `Parent` chain from the target, running each
+                        // ancestor's bubbling handlers at most once, until a handler calls
+                        // `stop_propagation` or the root (or a missing/cyclic parent) is reached.
+                        // Fallible (`try_add`) handlers don't participate in this walk — like
+                        // `list`, they only ever run on the original target entity.
+                        let mut ctx = BubbleContext { target, current_entity: target, stop: false };
+                        let mut visited = HashSet::new();
+                        let mut current = Some(target);
+                        while let Some(entity) = current {
+                            if !visited.insert(entity) {
+                                break;
+                            }
+                            ctx.current_entity = entity;
+                            if let Some(listener) = world.get_mut::<EventListener<E>>(entity) {
+                                let arc = listener.bubblers.clone();
+                                let mut bubblers = arc.lock().unwrap();
+                                for (_, handler) in bubblers.iter_mut() {
+                                    handler.on_event_bubble(world, &wrapped.event, &mut ctx);
+                                }
+                            }
+                            if ctx.stop {
+                                break;
+                            }
+                            current = world.get::<Parent>(entity).map(|parent| parent.get());
+                        }
+                    }
+                    None => {
+                        for &entity in entities.iter() {
+                            if let Some(listener) = world.get_mut::<EventListener<E>>(entity) {
+                                let list_arc = listener.list.clone();
+                                let fallible_arc = listener.fallible.clone();
+                                {
+                                    let mut list = list_arc.lock().unwrap();
+                                    for (_, handler) in list.iter_mut() {
+                                        handler.on_event(world, &wrapped.event, entity);
+                                    }
+                                }
+                                let mut fallible = fallible_arc.lock().unwrap();
+                                for (_, handler) in fallible.iter_mut() {
+                                    if let Err(error) = handler.try_on_event(world, &wrapped.event, entity) {
+                                        if let Some(mut errors) = world.get_resource_mut::<Events<ListenerError>>() {
+                                            errors.send(ListenerError { entity, event_type: TypeId::of::<E>(), error });
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
         });
     }
@@ -173,18 +725,127 @@ pub trait AddEventListenerExt {
     fn add_event_and_listen<E>(&mut self) -> &mut Self
     where
         E: 'static + Send + Sync;
+
+    fn add_targeted_event_and_listen<E>(&mut self) -> &mut Self
+    where
+        E: 'static + Send + Sync;
 }
 
 impl AddEventListenerExt for App {
     fn add_event_and_listen<E>(&mut self) -> &mut Self
     where
         E: 'static + Send + Sync
-    {   
-        self.add_event::<E>();     
+    {
+        self.add_event::<E>();
         self.world.get_resource_or_insert_with(EventProcessors::default)
         .map.insert(TypeId::of::<E>(), Box::new(Processor::<E>::default()));
         self
     }
+
+    fn add_targeted_event_and_listen<E>(&mut self) -> &mut Self
+    where
+        E: 'static + Send + Sync
+    {
+        self.add_event::<EntityEvent<E>>();
+        self.world.get_resource_or_insert_with(EventProcessors::default)
+        .map.insert(TypeId::of::<EntityEvent<E>>(), Box::new(TargetedProcessor::<E>::default()));
+        self
+    }
+}
+
+/// Fired at the entity that just had a `C` added to it, via the targeted
+/// event machinery; see `App::add_component_lifecycle_listen`.
+pub struct ComponentAdded<C> {
+    phantom: PhantomData<fn() -> C>,
+}
+
+impl <C> ComponentAdded<C> {
+    fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+/// Fired at the entity that just had a `C` removed from it, via the targeted
+/// event machinery; see `App::add_component_lifecycle_listen`.
+pub struct ComponentRemoved<C> {
+    phantom: PhantomData<fn() -> C>,
+}
+
+impl <C> ComponentRemoved<C> {
+    fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+impl <C> EventListener<ComponentAdded<C>>
+where
+    C: 'static + Send + Sync
+{
+    /// Registers a handler that fires when `C` is added to the entity this
+    /// listener is attached to; see `App::add_component_lifecycle_listen`.
+    pub fn on_added<F>(mut f: F) -> Self
+    where
+        F: FnMut(&mut World, Entity) + 'static + Send + Sync
+    {
+        let mut listener = Self::default();
+        let id = listener.next_listener_id();
+        let h = EventHandler::new(move |world: &mut World, _event: &ComponentAdded<C>, entity: Entity| f(world, entity));
+        listener.list.lock().unwrap().push((id, Box::new(h)));
+        listener
+    }
+}
+
+impl <C> EventListener<ComponentRemoved<C>>
+where
+    C: 'static + Send + Sync
+{
+    /// Registers a handler that fires when `C` is removed from the entity
+    /// this listener is attached to; see `App::add_component_lifecycle_listen`.
+    pub fn on_removed<F>(mut f: F) -> Self
+    where
+        F: FnMut(&mut World, Entity) + 'static + Send + Sync
+    {
+        let mut listener = Self::default();
+        let id = listener.next_listener_id();
+        let h = EventHandler::new(move |world: &mut World, _event: &ComponentRemoved<C>, entity: Entity| f(world, entity));
+        listener.list.lock().unwrap().push((id, Box::new(h)));
+        listener
+    }
+}
+
+fn detect_component_lifecycle<C: Component>(
+    added: Query<Entity, Added<C>>,
+    mut removed: RemovedComponents<C>,
+    mut added_writer: EventWriter<EntityEvent<ComponentAdded<C>>>,
+    mut removed_writer: EventWriter<EntityEvent<ComponentRemoved<C>>>,
+) {
+    for entity in added.iter() {
+        added_writer.send_to(entity, ComponentAdded::new());
+    }
+    for entity in removed.iter() {
+        removed_writer.send_to(entity, ComponentRemoved::new());
+    }
+}
+
+pub trait AddComponentLifecycleListenerExt {
+    fn add_component_lifecycle_listen<C>(&mut self) -> &mut Self
+    where
+        C: Component;
+}
+
+impl AddComponentLifecycleListenerExt for App {
+    fn add_component_lifecycle_listen<C>(&mut self) -> &mut Self
+    where
+        C: Component
+    {
+        self.add_targeted_event_and_listen::<ComponentAdded<C>>();
+        self.add_targeted_event_and_listen::<ComponentRemoved<C>>();
+        self.add_system_to_stage(
+            CoreStage::PreUpdate,
+            detect_component_lifecycle::<C>.before(UpdateGenericEventListeners)
+        );
+        self
+    }
 }
 
 pub struct EventListenerComponentPlugin;
@@ -195,6 +856,7 @@ pub struct UpdateGenericEventListeners;
 impl Plugin for EventListenerComponentPlugin {
     fn build(&self, app: &mut App) {
         app
+        .add_event::<ListenerError>()
         .add_system_to_stage(
             CoreStage::PreUpdate,
             update_event_listeners.exclusive_system().at_end()